@@ -1,5 +1,6 @@
 use std::io::{self, Write, Read, BufRead};
 use std::convert::{AsRef, AsMut};
+use std::ops::{Deref, DerefMut};
 
 pub use Either::{Left, Right};
 
@@ -80,6 +81,146 @@ impl<L, R> Either<L, R> {
             Right(r) => Left(r),
         }
     }
+
+    /// Apply `f` to the `Left` payload, leaving a `Right` value untouched.
+    pub fn map_left<F, L2>(self, f: F) -> Either<L2, R>
+        where F: FnOnce(L) -> L2
+    {
+        match self {
+            Left(l) => Left(f(l)),
+            Right(r) => Right(r),
+        }
+    }
+
+    /// Apply `f` to the `Right` payload, leaving a `Left` value untouched.
+    pub fn map_right<F, R2>(self, f: F) -> Either<L, R2>
+        where F: FnOnce(R) -> R2
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => Right(f(r)),
+        }
+    }
+
+    /// Apply `f` to a `Left` value or `g` to a `Right` value, producing a
+    /// new `Either`.
+    pub fn map_either<F, G, L2, R2>(self, f: F, g: G) -> Either<L2, R2>
+        where F: FnOnce(L) -> L2, G: FnOnce(R) -> R2
+    {
+        match self {
+            Left(l) => Left(f(l)),
+            Right(r) => Right(g(r)),
+        }
+    }
+
+    /// Apply the function `f` to a `Left` value, or the function `g` to a
+    /// `Right` value, collapsing both arms into a single result type.
+    pub fn either<T, F, G>(self, f: F, g: G) -> T
+        where F: FnOnce(L) -> T, G: FnOnce(R) -> T
+    {
+        match self {
+            Left(l) => f(l),
+            Right(r) => g(r),
+        }
+    }
+
+    /// Like `either`, but additionally takes an owned `ctx` that is
+    /// threaded into whichever of `f` or `g` ends up being called.
+    pub fn either_with<Ctx, T, F, G>(self, ctx: Ctx, f: F, g: G) -> T
+        where F: FnOnce(Ctx, L) -> T, G: FnOnce(Ctx, R) -> T
+    {
+        match self {
+            Left(l) => f(ctx, l),
+            Right(r) => g(ctx, r),
+        }
+    }
+
+    /// If `Left`, apply `f` to the payload and return its result,
+    /// otherwise pass a `Right` value through unchanged.
+    pub fn left_and_then<F, L2>(self, f: F) -> Either<L2, R>
+        where F: FnOnce(L) -> Either<L2, R>
+    {
+        match self {
+            Left(l) => f(l),
+            Right(r) => Right(r),
+        }
+    }
+
+    /// If `Right`, apply `f` to the payload and return its result,
+    /// otherwise pass a `Left` value through unchanged.
+    pub fn right_and_then<F, R2>(self, f: F) -> Either<L, R2>
+        where F: FnOnce(R) -> Either<L, R2>
+    {
+        match self {
+            Left(l) => Left(l),
+            Right(r) => f(r),
+        }
+    }
+}
+
+impl<T, L, R> Either<(T, L), (T, R)> {
+    /// Factor out a common first component of a pair held in both arms,
+    /// without having to match on the variant.
+    pub fn factor_first(self) -> (T, Either<L, R>) {
+        match self {
+            Left((t, l)) => (t, Left(l)),
+            Right((t, r)) => (t, Right(r)),
+        }
+    }
+}
+
+impl<T, L, R> Either<(L, T), (R, T)> {
+    /// Factor out a common second component of a pair held in both arms,
+    /// without having to match on the variant.
+    pub fn factor_second(self) -> (Either<L, R>, T) {
+        match self {
+            Left((l, t)) => (Left(l), t),
+            Right((r, t)) => (Right(r), t),
+        }
+    }
+}
+
+impl<L, R> Either<L, R> {
+    /// Factor an `Either` of two iterables sharing a common item shape
+    /// into a single iterator of `(K, Either<V1, V2>)` pairs, pulling the
+    /// shared first component `K` out of each item and keeping the payload
+    /// tagged with its side. This generalizes the `Iterator` impl for
+    /// `Either` to the case where the two sides differ only in a payload
+    /// component (e.g. `(K, V1)` vs. `(K, V2)`).
+    pub fn factor_into_iter(self) -> IterEither<L::IntoIter, R::IntoIter>
+        where L: IntoIterator, R: IntoIterator
+    {
+        match self {
+            Left(l) => IterEither { inner: Left(l.into_iter()) },
+            Right(r) => IterEither { inner: Right(r.into_iter()) },
+        }
+    }
+}
+
+/// An iterator that yields `(K, Either<V1, V2>)` out of whichever of two
+/// iterators of `(K, V1)` or `(K, V2)` items it holds.
+///
+/// See [`factor_into_iter`](enum.Either.html#method.factor_into_iter) for
+/// more information.
+pub struct IterEither<I, J> {
+    inner: Either<I, J>,
+}
+
+impl<I, J, K, V1, V2> Iterator for IterEither<I, J>
+    where I: Iterator<Item=(K, V1)>, J: Iterator<Item=(K, V2)>
+{
+    type Item = (K, Either<V1, V2>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            Left(ref mut i) => i.next().map(|(k, v)| (k, Left(v))),
+            Right(ref mut j) => j.next().map(|(k, v)| (k, Right(v))),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        either!(self.inner, inner => inner.size_hint())
+    }
 }
 
 /// Convert from `Result` to `Either` with `Ok => Right` and `Err => Left`.
@@ -184,6 +325,363 @@ impl<L, R, Target> AsMut<Target> for Either<L, R>
     }
 }
 
+/// `Either<L, R>` implements `Deref` when both `L` and `R` deref to the
+/// same target, so the wrapped value can be used as a `&Target`
+/// regardless of which variant is active.
+impl<L, R, Target: ?Sized> Deref for Either<L, R>
+    where L: Deref<Target=Target>, R: Deref<Target=Target>
+{
+    type Target = Target;
+
+    fn deref(&self) -> &Target {
+        either!(*self, inner => inner)
+    }
+}
+
+/// `Either<L, R>` implements `DerefMut` when both `L` and `R` deref to
+/// the same target.
+impl<L, R, Target: ?Sized> DerefMut for Either<L, R>
+    where L: DerefMut<Target=Target>, R: DerefMut<Target=Target>
+{
+    fn deref_mut(&mut self) -> &mut Target {
+        either_mut!(*self, inner => &mut *inner)
+    }
+}
+
+/// `EitherOrBoth<A, B>` represents a value that may hold the left value,
+/// the right value, or both at once.
+///
+/// This is useful for cases where `Either` isn't quite enough, such as
+/// zipping two iterators of unequal length or merging two sorted
+/// sequences, where a given position may come from the left side, the
+/// right side, or both sides simultaneously.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum EitherOrBoth<A, B> {
+    /// Only the left value.
+    Left(A),
+    /// Only the right value.
+    Right(B),
+    /// Both the left and the right value.
+    Both(A, B),
+}
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// Whether this contains a left value (i.e. `Left` or `Both`).
+    pub fn has_left(&self) -> bool {
+        match *self {
+            EitherOrBoth::Left(_) | EitherOrBoth::Both(_, _) => true,
+            EitherOrBoth::Right(_) => false,
+        }
+    }
+
+    /// Whether this contains a right value (i.e. `Right` or `Both`).
+    pub fn has_right(&self) -> bool {
+        match *self {
+            EitherOrBoth::Right(_) | EitherOrBoth::Both(_, _) => true,
+            EitherOrBoth::Left(_) => false,
+        }
+    }
+
+    /// Whether this is the `Left` variant.
+    pub fn is_left(&self) -> bool {
+        matches!(*self, EitherOrBoth::Left(_))
+    }
+
+    /// Whether this is the `Right` variant.
+    pub fn is_right(&self) -> bool {
+        matches!(*self, EitherOrBoth::Right(_))
+    }
+
+    /// Whether this is the `Both` variant.
+    pub fn is_both(&self) -> bool {
+        matches!(*self, EitherOrBoth::Both(_, _))
+    }
+
+    /// The left value, if any, dropping the right value if `Both`.
+    pub fn left(self) -> Option<A> {
+        match self {
+            EitherOrBoth::Left(a) | EitherOrBoth::Both(a, _) => Some(a),
+            EitherOrBoth::Right(_) => None,
+        }
+    }
+
+    /// The right value, if any, dropping the left value if `Both`.
+    pub fn right(self) -> Option<B> {
+        match self {
+            EitherOrBoth::Right(b) | EitherOrBoth::Both(_, b) => Some(b),
+            EitherOrBoth::Left(_) => None,
+        }
+    }
+
+    /// Both values, if this is the `Both` variant.
+    pub fn both(self) -> Option<(A, B)> {
+        match self {
+            EitherOrBoth::Both(a, b) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    pub fn as_ref(&self) -> EitherOrBoth<&A, &B> {
+        match *self {
+            EitherOrBoth::Left(ref a) => EitherOrBoth::Left(a),
+            EitherOrBoth::Right(ref b) => EitherOrBoth::Right(b),
+            EitherOrBoth::Both(ref a, ref b) => EitherOrBoth::Both(a, b),
+        }
+    }
+
+    pub fn as_mut(&mut self) -> EitherOrBoth<&mut A, &mut B> {
+        match *self {
+            EitherOrBoth::Left(ref mut a) => EitherOrBoth::Left(a),
+            EitherOrBoth::Right(ref mut b) => EitherOrBoth::Right(b),
+            EitherOrBoth::Both(ref mut a, ref mut b) => EitherOrBoth::Both(a, b),
+        }
+    }
+
+    /// Swap the left and right sides, reordering `Both` to match.
+    pub fn flip(self) -> EitherOrBoth<B, A> {
+        match self {
+            EitherOrBoth::Left(a) => EitherOrBoth::Right(a),
+            EitherOrBoth::Right(b) => EitherOrBoth::Left(b),
+            EitherOrBoth::Both(a, b) => EitherOrBoth::Both(b, a),
+        }
+    }
+
+    /// Map the left value, if any, leaving any right value untouched.
+    pub fn map_left<F, A2>(self, f: F) -> EitherOrBoth<A2, B>
+        where F: FnOnce(A) -> A2
+    {
+        match self {
+            EitherOrBoth::Left(a) => EitherOrBoth::Left(f(a)),
+            EitherOrBoth::Right(b) => EitherOrBoth::Right(b),
+            EitherOrBoth::Both(a, b) => EitherOrBoth::Both(f(a), b),
+        }
+    }
+
+    /// Map the right value, if any, leaving any left value untouched.
+    pub fn map_right<F, B2>(self, f: F) -> EitherOrBoth<A, B2>
+        where F: FnOnce(B) -> B2
+    {
+        match self {
+            EitherOrBoth::Left(a) => EitherOrBoth::Left(a),
+            EitherOrBoth::Right(b) => EitherOrBoth::Right(f(b)),
+            EitherOrBoth::Both(a, b) => EitherOrBoth::Both(a, f(b)),
+        }
+    }
+
+    /// Map whichever values are present, applying `f` to a left value and
+    /// `g` to a right value.
+    pub fn map_any<F, G, A2, B2>(self, f: F, g: G) -> EitherOrBoth<A2, B2>
+        where F: FnOnce(A) -> A2, G: FnOnce(B) -> B2
+    {
+        match self {
+            EitherOrBoth::Left(a) => EitherOrBoth::Left(f(a)),
+            EitherOrBoth::Right(b) => EitherOrBoth::Right(g(b)),
+            EitherOrBoth::Both(a, b) => EitherOrBoth::Both(f(a), g(b)),
+        }
+    }
+
+    /// Collapse into an `Either`, preferring the left value when both are
+    /// present.
+    pub fn or(self) -> Either<A, B> {
+        match self {
+            EitherOrBoth::Left(a) => Left(a),
+            EitherOrBoth::Right(b) => Right(b),
+            EitherOrBoth::Both(a, _) => Left(a),
+        }
+    }
+
+    /// Collapse into an `Either`, calling `f` to pick a side when both are
+    /// present.
+    pub fn or_else<F>(self, f: F) -> Either<A, B>
+        where F: FnOnce(A, B) -> Either<A, B>
+    {
+        match self {
+            EitherOrBoth::Left(a) => Left(a),
+            EitherOrBoth::Right(b) => Right(b),
+            EitherOrBoth::Both(a, b) => f(a, b),
+        }
+    }
+
+    /// The left value if present (dropping any right value if `Both`),
+    /// otherwise `default`.
+    pub fn left_or(self, default: A) -> A {
+        match self {
+            EitherOrBoth::Left(a) | EitherOrBoth::Both(a, _) => a,
+            EitherOrBoth::Right(_) => default,
+        }
+    }
+
+    /// The right value if present (dropping any left value if `Both`),
+    /// otherwise `default`.
+    pub fn right_or(self, default: B) -> B {
+        match self {
+            EitherOrBoth::Right(b) | EitherOrBoth::Both(_, b) => b,
+            EitherOrBoth::Left(_) => default,
+        }
+    }
+}
+
+/// Convert from `Either`, mapping `Left` and `Right` across unchanged.
+impl<A, B> From<Either<A, B>> for EitherOrBoth<A, B> {
+    fn from(e: Either<A, B>) -> Self {
+        match e {
+            Left(a) => EitherOrBoth::Left(a),
+            Right(b) => EitherOrBoth::Right(b),
+        }
+    }
+}
+
+/// An iterator adaptor that walks two iterators in lockstep, yielding
+/// `EitherOrBoth::Both` while both produce items, then `Left`/`Right`
+/// once one side is exhausted.
+///
+/// See [`zip_longest`](fn.zip_longest.html) for more information.
+pub struct ZipLongest<I, J> {
+    a: I,
+    b: J,
+}
+
+/// Create an iterator that yields `EitherOrBoth` items out of two
+/// iterators, continuing until both are exhausted.
+pub fn zip_longest<I, J>(i: I, j: J) -> ZipLongest<I::IntoIter, J::IntoIter>
+    where I: IntoIterator, J: IntoIterator
+{
+    ZipLongest {
+        a: i.into_iter(),
+        b: j.into_iter(),
+    }
+}
+
+impl<I, J> Iterator for ZipLongest<I, J>
+    where I: Iterator, J: Iterator
+{
+    type Item = EitherOrBoth<I::Item, J::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match (self.a.next(), self.b.next()) {
+            (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+            (Some(a), None) => Some(EitherOrBoth::Left(a)),
+            (None, Some(b)) => Some(EitherOrBoth::Right(b)),
+            (None, None) => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a_lower, a_upper) = self.a.size_hint();
+        let (b_lower, b_upper) = self.b.size_hint();
+
+        let lower = ::std::cmp::max(a_lower, b_lower);
+        let upper = match (a_upper, b_upper) {
+            (Some(a_upper), Some(b_upper)) => Some(::std::cmp::max(a_upper, b_upper)),
+            _ => None,
+        };
+
+        (lower, upper)
+    }
+}
+
+#[test]
+fn either_or_both() {
+    let mut iter = zip_longest(0..3, vec!["a", "b"]);
+
+    assert_eq!(iter.next(), Some(EitherOrBoth::Both(0, "a")));
+    assert_eq!(iter.next(), Some(EitherOrBoth::Both(1, "b")));
+    assert_eq!(iter.next(), Some(EitherOrBoth::Left(2)));
+    assert_eq!(iter.next(), None);
+
+    let left: EitherOrBoth<i32, &str> = EitherOrBoth::Left(1);
+    let right: EitherOrBoth<i32, &str> = EitherOrBoth::Right("x");
+    let both = EitherOrBoth::Both(1, "x");
+
+    assert!(left.is_left() && !left.is_right() && !left.is_both());
+    assert!(right.is_right() && !right.is_left() && !right.is_both());
+    assert!(both.has_left() && both.has_right() && both.is_both());
+    assert!(!left.has_right() && !right.has_left());
+
+    assert_eq!(left.both(), None);
+    assert_eq!(both.both(), Some((1, "x")));
+
+    assert_eq!(left.left_or(0), 1);
+    assert_eq!(right.left_or(0), 0);
+    assert_eq!(both.left_or(0), 1);
+    assert_eq!(left.right_or("y"), "y");
+    assert_eq!(right.right_or("y"), "x");
+    assert_eq!(both.right_or("y"), "x");
+
+    assert_eq!(left.or(), Left(1));
+    assert_eq!(right.or(), Right("x"));
+    assert_eq!(both.or(), Left(1));
+    assert_eq!(both.or_else(|_, b| Right(b)), Right("x"));
+    assert_eq!(left.or_else(|a, _| Left(a)), Left(1));
+
+    assert_eq!(both.as_ref(), EitherOrBoth::Both(&1, &"x"));
+    let mut mutable = EitherOrBoth::Both(1, "x");
+    assert_eq!(mutable.as_mut(), EitherOrBoth::Both(&mut 1, &mut "x"));
+
+    assert_eq!(both.map_left(|a| a + 1), EitherOrBoth::Both(2, "x"));
+    assert_eq!(left.map_left(|a| a + 1), EitherOrBoth::Left(2));
+    assert_eq!(both.map_right(|b| b.len()), EitherOrBoth::Both(1, 1));
+    assert_eq!(right.map_right(|b| b.len()), EitherOrBoth::Right(1));
+    assert_eq!(both.map_any(|a| a + 1, |b| b.len()), EitherOrBoth::Both(2, 1));
+
+    assert_eq!(both.flip(), EitherOrBoth::Both("x", 1));
+    assert_eq!(left.flip(), EitherOrBoth::Right(1));
+    assert_eq!(right.flip(), EitherOrBoth::Left("x"));
+
+    let from_left: EitherOrBoth<i32, &str> = EitherOrBoth::from(Left(1));
+    let from_right: EitherOrBoth<i32, &str> = EitherOrBoth::from(Right("x"));
+    assert_eq!(from_left, EitherOrBoth::Left(1));
+    assert_eq!(from_right, EitherOrBoth::Right("x"));
+}
+
+#[test]
+fn deref() {
+    let mut e: Either<String, &str> = Left(String::from("hello"));
+    assert_eq!(&*e, "hello");
+    e = Right("world");
+    assert_eq!(&*e, "world");
+
+    let mut boxed: Either<Box<i32>, Box<i32>> = Left(Box::new(2));
+    *boxed = 5;
+    assert_eq!(*boxed, 5);
+}
+
+#[test]
+fn map_and_either() {
+    let l: Either<i32, i32> = Left(2);
+    let r: Either<i32, i32> = Right(2);
+
+    assert_eq!(l.map_left(|x| x + 1), Left(3));
+    assert_eq!(r.map_left(|x| x + 1), Right(2));
+    assert_eq!(l.map_right(|x| x + 1), Left(2));
+    assert_eq!(r.map_right(|x| x + 1), Right(3));
+    assert_eq!(l.map_either(|x| x + 1, |x| x - 1), Left(3));
+    assert_eq!(r.map_either(|x| x + 1, |x| x - 1), Right(1));
+
+    assert_eq!(l.either(|x| x + 1, |x| x - 1), 3);
+    assert_eq!(r.either(|x| x + 1, |x| x - 1), 1);
+    assert_eq!(l.either_with(10, |ctx, x| ctx + x, |ctx, x| ctx - x), 12);
+
+    let l_chained: Either<i32, i32> = l.left_and_then(|x| Right(x + 1));
+    assert_eq!(l_chained, Right(3));
+    let r_chained: Either<i32, i32> = r.right_and_then(|x| Left(x + 1));
+    assert_eq!(r_chained, Left(3));
+}
+
+#[test]
+#[allow(clippy::type_complexity)]
+fn factor() {
+    let l: Either<(i32, &str), (i32, &str)> = Left((1, "a"));
+    assert_eq!(l.factor_first(), (1, Left("a")));
+
+    let r: Either<(&str, i32), (&str, i32)> = Right(("b", 2));
+    assert_eq!(r.factor_second(), (Right("b"), 2));
+
+    let e: Either<Vec<(i32, &str)>, Vec<(i32, &str)>> = Left(vec![(1, "a"), (2, "b")]);
+    let factored: Vec<_> = e.factor_into_iter().collect();
+    assert_eq!(factored, vec![(1, Left("a")), (2, Left("b"))]);
+}
+
 #[test]
 fn basic() {
     let mut e = Left(2);